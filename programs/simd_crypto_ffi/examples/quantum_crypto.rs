@@ -27,6 +27,7 @@
 //!    ```
 
 use std::ffi::CStr;
+use std::os::raw::c_void;
 
 // =============================================================================
 // FFI Declarations
@@ -37,6 +38,7 @@ extern "C" {
     fn quantum_sha256(input: *const u8, input_len: usize, output: *mut u8) -> i32;
     fn quantum_sha256d(input: *const u8, input_len: usize, output: *mut u8) -> i32;
     fn quantum_blake3(input: *const u8, input_len: usize, output: *mut u8) -> i32;
+    fn quantum_ripemd160(input: *const u8, input_len: usize, output: *mut u8) -> i32;
     fn quantum_hmac_sha256(
         key: *const u8,
         key_len: usize,
@@ -53,9 +55,31 @@ extern "C" {
         output: *mut u8,
         output_len: usize,
     ) -> i32;
+    fn quantum_constant_time_eq(a: *const u8, a_len: usize, b: *const u8, b_len: usize) -> i32;
     fn quantum_secure_zero(ptr: *mut u8, len: usize);
+    fn quantum_secure_lock(ptr: *mut u8, len: usize) -> i32;
+    fn quantum_secure_unlock(ptr: *mut u8, len: usize) -> i32;
+    fn quantum_random_bytes(output: *mut u8, output_len: usize) -> i32;
     fn quantum_version() -> *const std::os::raw::c_char;
     fn quantum_get_error(buf: *mut u8, buf_size: usize) -> usize;
+
+    fn quantum_sha256_init() -> *mut c_void;
+    fn quantum_sha256_update(ctx: *mut c_void, data: *const u8, data_len: usize) -> i32;
+    fn quantum_sha256_finalize(ctx: *mut c_void, output: *mut u8) -> i32;
+    fn quantum_sha256_reset(ctx: *mut c_void) -> i32;
+    fn quantum_sha256_free(ctx: *mut c_void);
+
+    fn quantum_sha256d_init() -> *mut c_void;
+    fn quantum_sha256d_update(ctx: *mut c_void, data: *const u8, data_len: usize) -> i32;
+    fn quantum_sha256d_finalize(ctx: *mut c_void, output: *mut u8) -> i32;
+    fn quantum_sha256d_reset(ctx: *mut c_void) -> i32;
+    fn quantum_sha256d_free(ctx: *mut c_void);
+
+    fn quantum_blake3_init() -> *mut c_void;
+    fn quantum_blake3_update(ctx: *mut c_void, data: *const u8, data_len: usize) -> i32;
+    fn quantum_blake3_finalize(ctx: *mut c_void, output: *mut u8) -> i32;
+    fn quantum_blake3_reset(ctx: *mut c_void) -> i32;
+    fn quantum_blake3_free(ctx: *mut c_void);
 }
 
 // =============================================================================
@@ -107,6 +131,53 @@ pub fn blake3(data: &[u8]) -> [u8; 32] {
     output
 }
 
+/// Compute RIPEMD-160 hash
+pub fn ripemd160(data: &[u8]) -> [u8; 20] {
+    let mut output = [0u8; 20];
+    unsafe {
+        quantum_ripemd160(data.as_ptr(), data.len(), output.as_mut_ptr());
+    }
+    output
+}
+
+/// Compute Bitcoin's Hash160: `RIPEMD160(SHA256(data))`.
+///
+/// This is the standard P2PKH/P2SH step used to derive Bitcoin addresses
+/// from a public key or redeem script.
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    ripemd160(&sha256(data))
+}
+
+/// Compute a Bitcoin-style Merkle root over already-hashed leaves.
+///
+/// Each input is treated as a leaf hash. The next level is built by
+/// concatenating consecutive pairs and hashing the 64-byte concatenation
+/// with [`sha256d`]; if a level has an odd number of nodes, the last node
+/// is duplicated before pairing, per Bitcoin's convention. This repeats
+/// until one node remains. An empty input returns all-zeros.
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(*level.last().unwrap());
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let mut concat = [0u8; 64];
+                concat[..32].copy_from_slice(&pair[0]);
+                concat[32..].copy_from_slice(&pair[1]);
+                sha256d(&concat)
+            })
+            .collect();
+    }
+    level[0]
+}
+
 /// Compute HMAC-SHA256
 ///
 /// Used for:
@@ -127,6 +198,36 @@ pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
     output
 }
 
+/// Compare two byte slices in constant time.
+///
+/// Where a naive `a == b` returns as soon as it finds a differing byte —
+/// leaking, byte by byte, where two secrets diverge — this scans every byte
+/// regardless of outcome. If the lengths differ the mismatch is folded into
+/// the accumulator but the scan still runs, so total work and branch
+/// behavior depend only on the input lengths, never on where (or whether)
+/// the first difference falls. Use this for MACs, auth tags, and password
+/// hashes instead of `==`.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    unsafe { quantum_constant_time_eq(a.as_ptr(), a.len(), b.as_ptr(), b.len()) == 1 }
+}
+
+/// Verify an HMAC-SHA256 tag in constant time.
+///
+/// Equivalent to `hmac_sha256(key, message) == expected_tag`, but safe to
+/// use on attacker-supplied tags since the comparison leaks no timing
+/// information about where the tags first diverge. Built on [`try_hmac_sha256`]
+/// rather than [`hmac_sha256`] so that a failed FFI call surfaces as an
+/// error instead of silently comparing against an all-zero MAC and
+/// returning `false` as if the tag just didn't match.
+pub fn verify_hmac_sha256(
+    key: &[u8],
+    message: &[u8],
+    expected_tag: &[u8; 32],
+) -> Result<bool, QuantumError> {
+    let computed = try_hmac_sha256(key, message)?;
+    Ok(constant_time_eq(&computed, expected_tag))
+}
+
 /// Derive key from password using PBKDF2-SHA256
 ///
 /// Used for BIP39: converting seed phrases to master keys.
@@ -195,25 +296,589 @@ pub fn get_error() -> Option<String> {
     }
 }
 
+// =============================================================================
+// Error Handling
+// =============================================================================
+
+/// Error returned when a `quantum_*` FFI call reports failure.
+///
+/// Most wrappers in this file call into a Zig function that returns an
+/// `i32` status code and populates [`get_error`] on failure. The `try_*`
+/// functions (and the streaming/AEAD APIs) check that code and surface it
+/// as a `QuantumError` instead of silently handing back zero-filled
+/// output, so a caller can tell a real result from a failed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuantumError(String);
+
+impl QuantumError {
+    fn from_ffi(fallback: &str) -> Self {
+        Self(get_error().unwrap_or_else(|| fallback.to_string()))
+    }
+
+    /// The underlying error message.
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for QuantumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for QuantumError {}
+
+/// Like [`sha256`], but returns an error instead of silently succeeding
+/// with a zero-filled digest when the FFI call reports failure.
+pub fn try_sha256(data: &[u8]) -> Result<[u8; 32], QuantumError> {
+    let mut output = [0u8; 32];
+    let rc = unsafe { quantum_sha256(data.as_ptr(), data.len(), output.as_mut_ptr()) };
+    if rc != 0 {
+        return Err(QuantumError::from_ffi("sha256 failed"));
+    }
+    Ok(output)
+}
+
+/// Like [`sha256d`], but returns an error instead of silently succeeding
+/// with a zero-filled digest when the FFI call reports failure.
+pub fn try_sha256d(data: &[u8]) -> Result<[u8; 32], QuantumError> {
+    let mut output = [0u8; 32];
+    let rc = unsafe { quantum_sha256d(data.as_ptr(), data.len(), output.as_mut_ptr()) };
+    if rc != 0 {
+        return Err(QuantumError::from_ffi("sha256d failed"));
+    }
+    Ok(output)
+}
+
+/// Like [`blake3`], but returns an error instead of silently succeeding
+/// with a zero-filled digest when the FFI call reports failure.
+pub fn try_blake3(data: &[u8]) -> Result<[u8; 32], QuantumError> {
+    let mut output = [0u8; 32];
+    let rc = unsafe { quantum_blake3(data.as_ptr(), data.len(), output.as_mut_ptr()) };
+    if rc != 0 {
+        return Err(QuantumError::from_ffi("blake3 failed"));
+    }
+    Ok(output)
+}
+
+/// Like [`ripemd160`], but returns an error instead of silently succeeding
+/// with a zero-filled digest when the FFI call reports failure.
+pub fn try_ripemd160(data: &[u8]) -> Result<[u8; 20], QuantumError> {
+    let mut output = [0u8; 20];
+    let rc = unsafe { quantum_ripemd160(data.as_ptr(), data.len(), output.as_mut_ptr()) };
+    if rc != 0 {
+        return Err(QuantumError::from_ffi("ripemd160 failed"));
+    }
+    Ok(output)
+}
+
+/// Like [`hmac_sha256`], but returns an error instead of silently
+/// succeeding with a zero-filled MAC when the FFI call reports failure.
+pub fn try_hmac_sha256(key: &[u8], message: &[u8]) -> Result<[u8; 32], QuantumError> {
+    let mut output = [0u8; 32];
+    let rc = unsafe {
+        quantum_hmac_sha256(
+            key.as_ptr(),
+            key.len(),
+            message.as_ptr(),
+            message.len(),
+            output.as_mut_ptr(),
+        )
+    };
+    if rc != 0 {
+        return Err(QuantumError::from_ffi("hmac_sha256 failed"));
+    }
+    Ok(output)
+}
+
+/// Like [`pbkdf2_sha256`], but returns an error instead of silently
+/// succeeding with a zero-filled (or otherwise weak) key.
+///
+/// This matters more than the other `try_*` variants: a zero or absurd
+/// `iterations` count, or an `output_len` the Zig side rejects, would
+/// otherwise come back as an all-zero "key" that looks valid.
+pub fn try_pbkdf2_sha256(
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    output_len: usize,
+) -> Result<Vec<u8>, QuantumError> {
+    let mut output = vec![0u8; output_len];
+    let rc = unsafe {
+        quantum_pbkdf2_sha256(
+            password.as_ptr(),
+            password.len(),
+            salt.as_ptr(),
+            salt.len(),
+            iterations,
+            output.as_mut_ptr(),
+            output_len,
+        )
+    };
+    if rc != 0 {
+        return Err(QuantumError::from_ffi("pbkdf2_sha256 failed"));
+    }
+    Ok(output)
+}
+
+// =============================================================================
+// Streaming (Incremental) Hashers
+// =============================================================================
+
+fn ctx_error(fallback: &str) -> QuantumError {
+    QuantumError::from_ffi(fallback)
+}
+
+/// Incremental SHA-256 hasher.
+///
+/// Use this instead of [`sha256`] when the input arrives in chunks (e.g.
+/// streamed off disk) rather than as one buffered slice.
+///
+/// # Examples
+///
+/// ```
+/// let mut h = quantum_crypto::Sha256Ctx::new();
+/// h.update(b"hello ").unwrap();
+/// h.update(b"world").unwrap();
+/// let digest = h.finalize().unwrap();
+/// assert_eq!(digest, quantum_crypto::sha256(b"hello world"));
+/// ```
+pub struct Sha256Ctx {
+    ctx: *mut c_void,
+    finalized: bool,
+}
+
+impl Sha256Ctx {
+    /// Start a new incremental hash.
+    pub fn new() -> Self {
+        Self {
+            ctx: unsafe { quantum_sha256_init() },
+            finalized: false,
+        }
+    }
+
+    /// Feed more input into the hash.
+    ///
+    /// Returns an error if called after [`Sha256Ctx::finalize`] without an
+    /// intervening [`Sha256Ctx::reset`].
+    pub fn update(&mut self, data: &[u8]) -> Result<(), QuantumError> {
+        if self.finalized {
+            return Err(QuantumError("Sha256Ctx::update called after finalize without reset".to_string()));
+        }
+        let rc = unsafe { quantum_sha256_update(self.ctx, data.as_ptr(), data.len()) };
+        if rc != 0 {
+            return Err(ctx_error("sha256 update failed"));
+        }
+        Ok(())
+    }
+
+    /// Finish the hash and return the 32-byte digest.
+    ///
+    /// Returns an error if called twice without an intervening
+    /// [`Sha256Ctx::reset`].
+    pub fn finalize(&mut self) -> Result<[u8; 32], QuantumError> {
+        if self.finalized {
+            return Err(QuantumError("Sha256Ctx::finalize called twice without reset".to_string()));
+        }
+        let mut output = [0u8; 32];
+        let rc = unsafe { quantum_sha256_finalize(self.ctx, output.as_mut_ptr()) };
+        if rc != 0 {
+            return Err(ctx_error("sha256 finalize failed"));
+        }
+        self.finalized = true;
+        Ok(output)
+    }
+
+    /// Reset the hasher so it can be reused for a new message.
+    pub fn reset(&mut self) -> Result<(), QuantumError> {
+        let rc = unsafe { quantum_sha256_reset(self.ctx) };
+        if rc != 0 {
+            return Err(ctx_error("sha256 reset failed"));
+        }
+        self.finalized = false;
+        Ok(())
+    }
+}
+
+impl Default for Sha256Ctx {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Sha256Ctx {
+    fn drop(&mut self) {
+        unsafe { quantum_sha256_free(self.ctx) };
+    }
+}
+
+/// Incremental SHA-256d (double SHA-256) hasher. See [`Sha256Ctx`].
+pub struct Sha256dCtx {
+    ctx: *mut c_void,
+    finalized: bool,
+}
+
+impl Sha256dCtx {
+    /// Start a new incremental hash.
+    pub fn new() -> Self {
+        Self {
+            ctx: unsafe { quantum_sha256d_init() },
+            finalized: false,
+        }
+    }
+
+    /// Feed more input into the hash.
+    pub fn update(&mut self, data: &[u8]) -> Result<(), QuantumError> {
+        if self.finalized {
+            return Err(QuantumError("Sha256dCtx::update called after finalize without reset".to_string()));
+        }
+        let rc = unsafe { quantum_sha256d_update(self.ctx, data.as_ptr(), data.len()) };
+        if rc != 0 {
+            return Err(ctx_error("sha256d update failed"));
+        }
+        Ok(())
+    }
+
+    /// Finish the hash and return the 32-byte digest.
+    pub fn finalize(&mut self) -> Result<[u8; 32], QuantumError> {
+        if self.finalized {
+            return Err(QuantumError("Sha256dCtx::finalize called twice without reset".to_string()));
+        }
+        let mut output = [0u8; 32];
+        let rc = unsafe { quantum_sha256d_finalize(self.ctx, output.as_mut_ptr()) };
+        if rc != 0 {
+            return Err(ctx_error("sha256d finalize failed"));
+        }
+        self.finalized = true;
+        Ok(output)
+    }
+
+    /// Reset the hasher so it can be reused for a new message.
+    pub fn reset(&mut self) -> Result<(), QuantumError> {
+        let rc = unsafe { quantum_sha256d_reset(self.ctx) };
+        if rc != 0 {
+            return Err(ctx_error("sha256d reset failed"));
+        }
+        self.finalized = false;
+        Ok(())
+    }
+}
+
+impl Default for Sha256dCtx {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Sha256dCtx {
+    fn drop(&mut self) {
+        unsafe { quantum_sha256d_free(self.ctx) };
+    }
+}
+
+/// Incremental BLAKE3 hasher. See [`Sha256Ctx`].
+pub struct Blake3Ctx {
+    ctx: *mut c_void,
+    finalized: bool,
+}
+
+impl Blake3Ctx {
+    /// Start a new incremental hash.
+    pub fn new() -> Self {
+        Self {
+            ctx: unsafe { quantum_blake3_init() },
+            finalized: false,
+        }
+    }
+
+    /// Feed more input into the hash.
+    pub fn update(&mut self, data: &[u8]) -> Result<(), QuantumError> {
+        if self.finalized {
+            return Err(QuantumError("Blake3Ctx::update called after finalize without reset".to_string()));
+        }
+        let rc = unsafe { quantum_blake3_update(self.ctx, data.as_ptr(), data.len()) };
+        if rc != 0 {
+            return Err(ctx_error("blake3 update failed"));
+        }
+        Ok(())
+    }
+
+    /// Finish the hash and return the 32-byte digest.
+    pub fn finalize(&mut self) -> Result<[u8; 32], QuantumError> {
+        if self.finalized {
+            return Err(QuantumError("Blake3Ctx::finalize called twice without reset".to_string()));
+        }
+        let mut output = [0u8; 32];
+        let rc = unsafe { quantum_blake3_finalize(self.ctx, output.as_mut_ptr()) };
+        if rc != 0 {
+            return Err(ctx_error("blake3 finalize failed"));
+        }
+        self.finalized = true;
+        Ok(output)
+    }
+
+    /// Reset the hasher so it can be reused for a new message.
+    pub fn reset(&mut self) -> Result<(), QuantumError> {
+        let rc = unsafe { quantum_blake3_reset(self.ctx) };
+        if rc != 0 {
+            return Err(ctx_error("blake3 reset failed"));
+        }
+        self.finalized = false;
+        Ok(())
+    }
+}
+
+impl Default for Blake3Ctx {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Blake3Ctx {
+    fn drop(&mut self) {
+        unsafe { quantum_blake3_free(self.ctx) };
+    }
+}
+
+// =============================================================================
+// secp256k1 Signatures
+// =============================================================================
+
+/// ECDSA signing over secp256k1.
+///
+/// Backs BIP32 HD wallet derivation, Bitcoin transaction signing, and API
+/// request signing for the Quantum Vault project.
+pub mod secp256k1 {
+    use super::{QuantumError, SecureBytes};
+
+    #[link(name = "quantum_crypto", kind = "static")]
+    extern "C" {
+        fn quantum_secp256k1_pubkey_create(seckey: *const u8, pubkey_out: *mut u8) -> i32;
+        fn quantum_secp256k1_sign_ecdsa(msg32: *const u8, seckey: *const u8, sig_out: *mut u8) -> i32;
+        fn quantum_secp256k1_verify_ecdsa(msg32: *const u8, sig: *const u8, pubkey: *const u8) -> i32;
+    }
+
+    /// A secp256k1 secret (private) key: 32 bytes of key material.
+    ///
+    /// Key material is held in a [`SecureBytes`] so it is zeroed on drop.
+    /// Deliberately does **not** implement `Ord`, `PartialOrd`, or `Hash` —
+    /// ordering or hashing secret key material has no legitimate use and
+    /// only adds attack surface. Equality is constant-time so that comparing
+    /// keys can never leak them byte-by-byte through timing.
+    pub struct SecretKey(SecureBytes);
+
+    impl SecretKey {
+        /// Wrap raw secret key bytes.
+        pub fn from_bytes(bytes: [u8; 32]) -> Self {
+            Self(SecureBytes::new(bytes.to_vec()))
+        }
+
+        /// Borrow the raw 32 bytes of key material.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.0.as_slice()
+        }
+    }
+
+    impl PartialEq for SecretKey {
+        fn eq(&self, other: &Self) -> bool {
+            super::constant_time_eq(self.0.as_slice(), other.0.as_slice())
+        }
+    }
+
+    impl Eq for SecretKey {}
+
+    /// A secp256k1 public key in compressed (33-byte) form.
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct PublicKey([u8; 33]);
+
+    impl PublicKey {
+        /// Wrap raw compressed public key bytes.
+        pub fn from_bytes(bytes: [u8; 33]) -> Self {
+            Self(bytes)
+        }
+
+        /// Borrow the raw 33 compressed bytes.
+        pub fn as_bytes(&self) -> &[u8; 33] {
+            &self.0
+        }
+    }
+
+    /// Derive the compressed public key for a secret key.
+    ///
+    /// Returns an error instead of a zero-filled key if the FFI call fails.
+    pub fn public_from_secret(secret: &SecretKey) -> Result<PublicKey, QuantumError> {
+        let mut output = [0u8; 33];
+        let rc = unsafe { quantum_secp256k1_pubkey_create(secret.as_bytes().as_ptr(), output.as_mut_ptr()) };
+        if rc != 0 {
+            return Err(QuantumError::from_ffi("secp256k1 public key derivation failed"));
+        }
+        Ok(PublicKey(output))
+    }
+
+    /// Sign a 32-byte message hash, producing a compact 64-byte ECDSA signature.
+    ///
+    /// Returns an error instead of a zero-filled signature if the FFI call
+    /// fails — a silently wrong signature is the one failure mode this API
+    /// cannot afford.
+    pub fn sign_ecdsa(msg32: &[u8; 32], secret: &SecretKey) -> Result<[u8; 64], QuantumError> {
+        let mut sig = [0u8; 64];
+        let rc =
+            unsafe { quantum_secp256k1_sign_ecdsa(msg32.as_ptr(), secret.as_bytes().as_ptr(), sig.as_mut_ptr()) };
+        if rc != 0 {
+            return Err(QuantumError::from_ffi("secp256k1 ECDSA signing failed"));
+        }
+        Ok(sig)
+    }
+
+    /// Verify a compact 64-byte ECDSA signature against a message hash and public key.
+    ///
+    /// Returns `Ok(true)`/`Ok(false)` for a valid/invalid signature, or an
+    /// error if the FFI call itself failed (e.g. a malformed public key) —
+    /// distinct from a signature that merely fails to verify.
+    pub fn verify_ecdsa(msg32: &[u8; 32], sig: &[u8; 64], public: &PublicKey) -> Result<bool, QuantumError> {
+        let rc = unsafe { quantum_secp256k1_verify_ecdsa(msg32.as_ptr(), sig.as_ptr(), public.0.as_ptr()) };
+        match rc {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(QuantumError::from_ffi("secp256k1 ECDSA verification failed")),
+        }
+    }
+}
+
+// =============================================================================
+// AES-256-GCM Authenticated Encryption
+// =============================================================================
+
+/// AES-256-GCM authenticated encryption for vault storage at rest.
+///
+/// Pair with the existing [`pbkdf2_sha256`] to derive a 256-bit key from a
+/// passphrase, then encrypt a wallet seed or other secret end-to-end.
+pub mod aead {
+    use super::{QuantumError, SecureBytes};
+
+    #[link(name = "quantum_crypto", kind = "static")]
+    extern "C" {
+        fn quantum_aes256_gcm_encrypt(
+            key: *const u8,
+            nonce: *const u8,
+            aad: *const u8,
+            aad_len: usize,
+            plaintext: *const u8,
+            plaintext_len: usize,
+            output: *mut u8,
+        ) -> i32;
+        fn quantum_aes256_gcm_decrypt(
+            key: *const u8,
+            nonce: *const u8,
+            aad: *const u8,
+            aad_len: usize,
+            ciphertext: *const u8,
+            ciphertext_len: usize,
+            output: *mut u8,
+        ) -> i32;
+    }
+
+    /// Encrypt `plaintext`, returning ciphertext with the 16-byte
+    /// authentication tag appended.
+    ///
+    /// Returns an error instead of a zero-filled buffer if the FFI call
+    /// fails.
+    pub fn aes256_gcm_encrypt(
+        key: &[u8; 32],
+        nonce: &[u8; 12],
+        aad: &[u8],
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, QuantumError> {
+        let mut output = vec![0u8; plaintext.len() + 16];
+        let rc = unsafe {
+            quantum_aes256_gcm_encrypt(
+                key.as_ptr(),
+                nonce.as_ptr(),
+                aad.as_ptr(),
+                aad.len(),
+                plaintext.as_ptr(),
+                plaintext.len(),
+                output.as_mut_ptr(),
+            )
+        };
+        if rc != 0 {
+            return Err(QuantumError::from_ffi("AES-256-GCM encryption failed"));
+        }
+        Ok(output)
+    }
+
+    /// Decrypt `ciphertext_with_tag` (as produced by [`aes256_gcm_encrypt`]).
+    ///
+    /// The authentication tag is verified in constant time. On mismatch this
+    /// returns an error rather than partial or tampered plaintext; the
+    /// recovered plaintext is returned in a [`SecureBytes`] so it is zeroed
+    /// on drop.
+    pub fn aes256_gcm_decrypt(
+        key: &[u8; 32],
+        nonce: &[u8; 12],
+        aad: &[u8],
+        ciphertext_with_tag: &[u8],
+    ) -> Result<SecureBytes, QuantumError> {
+        if ciphertext_with_tag.len() < 16 {
+            return Err(QuantumError("ciphertext shorter than the 16-byte authentication tag".to_string()));
+        }
+        let plaintext_len = ciphertext_with_tag.len() - 16;
+        let mut output = vec![0u8; plaintext_len];
+        let rc = unsafe {
+            quantum_aes256_gcm_decrypt(
+                key.as_ptr(),
+                nonce.as_ptr(),
+                aad.as_ptr(),
+                aad.len(),
+                ciphertext_with_tag.as_ptr(),
+                ciphertext_with_tag.len(),
+                output.as_mut_ptr(),
+            )
+        };
+        if rc != 0 {
+            return Err(QuantumError::from_ffi("AES-256-GCM authentication tag mismatch"));
+        }
+        Ok(SecureBytes::new(output))
+    }
+}
+
 // =============================================================================
 // RAII Wrapper for Secure Memory
 // =============================================================================
 
-/// Wrapper that automatically zeros memory on drop
+/// Wrapper that pins its memory out of swap and zeros it on drop
 ///
-/// Use this for sensitive data like private keys.
+/// Use this for sensitive data like private keys. On construction the
+/// backing allocation is locked with `mlock`/`VirtualLock` (via
+/// `quantum_secure_lock`) so it cannot be paged to disk or captured in a
+/// swap file while alive; on drop it is zeroed and unlocked.
 ///
 /// # Examples
 ///
 /// ```
 /// let mut key = SecureBytes::new(vec![1, 2, 3, 4, 5]);
 /// // ... use key ...
-/// // Automatically zeroed when dropped
+/// // Automatically unlocked and zeroed when dropped
 /// ```
 pub struct SecureBytes(Vec<u8>);
 
 impl SecureBytes {
-    pub fn new(data: Vec<u8>) -> Self {
+    /// # Panics
+    ///
+    /// Panics if the backing allocation cannot be locked (e.g. `mlock`
+    /// rejected by `RLIMIT_MEMLOCK`, commonly 64 KiB or 0 in containers).
+    /// Returning an unlocked `SecureBytes` while documenting it as locked
+    /// would be worse than failing loudly: the one guarantee this type
+    /// exists to provide would silently not hold.
+    pub fn new(mut data: Vec<u8>) -> Self {
+        data.shrink_to_fit();
+        if !data.is_empty() {
+            let rc = unsafe { quantum_secure_lock(data.as_mut_ptr(), data.len()) };
+            if rc != 0 {
+                panic!("{}", QuantumError::from_ffi("quantum_secure_lock failed"));
+            }
+        }
         Self(data)
     }
 
@@ -229,6 +894,11 @@ impl SecureBytes {
 impl Drop for SecureBytes {
     fn drop(&mut self) {
         secure_zero(&mut self.0);
+        if !self.0.is_empty() {
+            unsafe {
+                quantum_secure_unlock(self.0.as_mut_ptr(), self.0.len());
+            }
+        }
     }
 }
 
@@ -246,6 +916,75 @@ impl std::ops::DerefMut for SecureBytes {
     }
 }
 
+/// Fill `out` with random bytes, panicking if the FFI call fails.
+///
+/// A failed RNG call must never be allowed to fall through to zero-filled
+/// "random" material — for an `Encrypted` nonce that means nonce reuse
+/// under the same key, which breaks AES-GCM's confidentiality and
+/// authenticity guarantees outright. There is no safe value to fall back
+/// to, so this panics instead.
+fn random_bytes(out: &mut [u8]) {
+    let rc = unsafe { quantum_random_bytes(out.as_mut_ptr(), out.len()) };
+    if rc != 0 {
+        panic!("{}", QuantumError::from_ffi("quantum_random_bytes failed"));
+    }
+}
+
+/// The key a process-lifetime [`Encrypted`] ciphertext is sealed under.
+///
+/// Generated once per process and never persisted or exposed; it exists
+/// only so an `Encrypted` secret's plaintext need not stay resident in
+/// memory for its whole lifetime.
+fn process_key() -> &'static [u8; 32] {
+    use std::sync::OnceLock;
+    static KEY: OnceLock<[u8; 32]> = OnceLock::new();
+    KEY.get_or_init(|| {
+        let mut key = [0u8; 32];
+        random_bytes(&mut key);
+        key
+    })
+}
+
+/// A secret kept encrypted in memory except for brief, locked decryptions.
+///
+/// Use this instead of [`SecureBytes`] for long-lived secrets (e.g. a
+/// derived wallet key held for the life of a session), where keeping the
+/// plaintext resident the whole time would widen the window an attacker
+/// has to recover it from process memory. The plaintext is sealed under an
+/// ephemeral, process-lifetime key; [`Encrypted::map`] decrypts it into a
+/// locked [`SecureBytes`] only for the duration of the supplied closure,
+/// zeroing it again the instant the closure returns.
+///
+/// # Examples
+///
+/// ```
+/// let secret = quantum_crypto::Encrypted::new(b"wallet seed");
+/// let digest = secret.map(|plaintext| quantum_crypto::sha256(plaintext));
+/// ```
+pub struct Encrypted {
+    nonce: [u8; 12],
+    ciphertext: Vec<u8>,
+}
+
+impl Encrypted {
+    /// Seal `plaintext` under the process's ephemeral key.
+    pub fn new(plaintext: &[u8]) -> Self {
+        let mut nonce = [0u8; 12];
+        random_bytes(&mut nonce);
+        let ciphertext = aead::aes256_gcm_encrypt(process_key(), &nonce, &[], plaintext)
+            .expect("AES-256-GCM encryption under a freshly generated key/nonce should never fail");
+        Self { nonce, ciphertext }
+    }
+
+    /// Decrypt into a locked [`SecureBytes`], hand it to `f`, then re-zero
+    /// and unlock it before returning `f`'s result.
+    pub fn map<R>(&self, f: impl FnOnce(&SecureBytes) -> R) -> R {
+        let plaintext = aead::aes256_gcm_decrypt(process_key(), &self.nonce, &[], &self.ciphertext)
+            .expect("Encrypted ciphertext is sealed under its own process key and must decrypt");
+        f(&plaintext)
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -293,4 +1032,251 @@ mod tests {
         let ver = version();
         assert!(ver.contains("quantum-crypto"));
     }
+
+    #[test]
+    fn test_secp256k1_sign_verify_roundtrip() {
+        let secret = secp256k1::SecretKey::from_bytes([1u8; 32]);
+        let public = secp256k1::public_from_secret(&secret).unwrap();
+        let msg = sha256(b"hello world");
+        let sig = secp256k1::sign_ecdsa(&msg, &secret).unwrap();
+        assert!(secp256k1::verify_ecdsa(&msg, &sig, &public).unwrap());
+    }
+
+    #[test]
+    fn test_secp256k1_verify_rejects_wrong_message() {
+        let secret = secp256k1::SecretKey::from_bytes([2u8; 32]);
+        let public = secp256k1::public_from_secret(&secret).unwrap();
+        let sig = secp256k1::sign_ecdsa(&sha256(b"hello world"), &secret).unwrap();
+        assert!(!secp256k1::verify_ecdsa(&sha256(b"goodbye world"), &sig, &public).unwrap());
+    }
+
+    #[test]
+    fn test_ripemd160() {
+        let hash = ripemd160(b"hello world");
+        assert_ne!(&hash[..], &[0u8; 20][..]);
+    }
+
+    #[test]
+    fn test_hash160_matches_composition() {
+        assert_eq!(hash160(b"hello world"), ripemd160(&sha256(b"hello world")));
+    }
+
+    #[test]
+    fn test_merkle_root_empty_is_zero() {
+        assert_eq!(merkle_root(&[]), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_merkle_root_single_leaf_is_itself() {
+        let leaf = sha256d(b"tx1");
+        assert_eq!(merkle_root(&[leaf]), leaf);
+    }
+
+    #[test]
+    fn test_merkle_root_odd_leaf_count_duplicates_last() {
+        let a = sha256d(b"tx1");
+        let b = sha256d(b"tx2");
+        let c = sha256d(b"tx3");
+
+        let mut concat_ab = [0u8; 64];
+        concat_ab[..32].copy_from_slice(&a);
+        concat_ab[32..].copy_from_slice(&b);
+        let ab = sha256d(&concat_ab);
+
+        let mut concat_cc = [0u8; 64];
+        concat_cc[..32].copy_from_slice(&c);
+        concat_cc[32..].copy_from_slice(&c);
+        let cc = sha256d(&concat_cc);
+
+        let mut concat_root = [0u8; 64];
+        concat_root[..32].copy_from_slice(&ab);
+        concat_root[32..].copy_from_slice(&cc);
+        let expected = sha256d(&concat_root);
+
+        assert_eq!(merkle_root(&[a, b, c]), expected);
+    }
+
+    #[test]
+    fn test_constant_time_eq_equal_and_unequal() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"wrong!"));
+        assert!(!constant_time_eq(b"short", b"a-longer-slice"));
+    }
+
+    #[test]
+    fn test_verify_hmac_sha256() {
+        let tag = hmac_sha256(b"secret", b"hello world");
+        assert!(verify_hmac_sha256(b"secret", b"hello world", &tag).unwrap());
+        assert!(!verify_hmac_sha256(b"secret", b"tampered", &tag).unwrap());
+    }
+
+    #[test]
+    fn test_sha256_ctx_matches_one_shot() {
+        let mut h = Sha256Ctx::new();
+        h.update(b"hello ").unwrap();
+        h.update(b"world").unwrap();
+        let digest = h.finalize().unwrap();
+        assert_eq!(digest, sha256(b"hello world"));
+    }
+
+    #[test]
+    fn test_sha256_ctx_reset_allows_reuse() {
+        let mut h = Sha256Ctx::new();
+        h.update(b"first message").unwrap();
+        h.finalize().unwrap();
+        h.reset().unwrap();
+        h.update(b"hello world").unwrap();
+        assert_eq!(h.finalize().unwrap(), sha256(b"hello world"));
+    }
+
+    #[test]
+    fn test_sha256_ctx_double_finalize_errors() {
+        let mut h = Sha256Ctx::new();
+        h.update(b"hello world").unwrap();
+        h.finalize().unwrap();
+        assert!(h.finalize().is_err());
+    }
+
+    #[test]
+    fn test_sha256_ctx_update_after_finalize_errors() {
+        let mut h = Sha256Ctx::new();
+        h.update(b"hello world").unwrap();
+        h.finalize().unwrap();
+        assert!(h.update(b"more").is_err());
+    }
+
+    #[test]
+    fn test_sha256d_ctx_matches_one_shot() {
+        let mut h = Sha256dCtx::new();
+        h.update(b"hello world").unwrap();
+        assert_eq!(h.finalize().unwrap(), sha256d(b"hello world"));
+    }
+
+    #[test]
+    fn test_sha256d_ctx_reset_allows_reuse() {
+        let mut h = Sha256dCtx::new();
+        h.update(b"first message").unwrap();
+        h.finalize().unwrap();
+        h.reset().unwrap();
+        h.update(b"hello world").unwrap();
+        assert_eq!(h.finalize().unwrap(), sha256d(b"hello world"));
+    }
+
+    #[test]
+    fn test_sha256d_ctx_double_finalize_errors() {
+        let mut h = Sha256dCtx::new();
+        h.update(b"hello world").unwrap();
+        h.finalize().unwrap();
+        assert!(h.finalize().is_err());
+    }
+
+    #[test]
+    fn test_sha256d_ctx_update_after_finalize_errors() {
+        let mut h = Sha256dCtx::new();
+        h.update(b"hello world").unwrap();
+        h.finalize().unwrap();
+        assert!(h.update(b"more").is_err());
+    }
+
+    #[test]
+    fn test_blake3_ctx_matches_one_shot() {
+        let mut h = Blake3Ctx::new();
+        h.update(b"hello world").unwrap();
+        assert_eq!(h.finalize().unwrap(), blake3(b"hello world"));
+    }
+
+    #[test]
+    fn test_blake3_ctx_reset_allows_reuse() {
+        let mut h = Blake3Ctx::new();
+        h.update(b"first message").unwrap();
+        h.finalize().unwrap();
+        h.reset().unwrap();
+        h.update(b"hello world").unwrap();
+        assert_eq!(h.finalize().unwrap(), blake3(b"hello world"));
+    }
+
+    #[test]
+    fn test_blake3_ctx_double_finalize_errors() {
+        let mut h = Blake3Ctx::new();
+        h.update(b"hello world").unwrap();
+        h.finalize().unwrap();
+        assert!(h.finalize().is_err());
+    }
+
+    #[test]
+    fn test_blake3_ctx_update_after_finalize_errors() {
+        let mut h = Blake3Ctx::new();
+        h.update(b"hello world").unwrap();
+        h.finalize().unwrap();
+        assert!(h.update(b"more").is_err());
+    }
+
+    #[test]
+    fn test_try_sha256_matches_one_shot() {
+        assert_eq!(try_sha256(b"hello world").unwrap(), sha256(b"hello world"));
+    }
+
+    #[test]
+    fn test_try_pbkdf2_sha256_matches_one_shot() {
+        let via_try = try_pbkdf2_sha256(b"password", b"salt", 10, 32).unwrap();
+        assert_eq!(via_try, pbkdf2_sha256(b"password", b"salt", 10, 32));
+    }
+
+    #[test]
+    fn test_sha256_ctx_double_finalize_error_message() {
+        let mut h = Sha256Ctx::new();
+        h.update(b"hello world").unwrap();
+        h.finalize().unwrap();
+        let err = h.finalize().unwrap_err();
+        assert!(!err.message().is_empty());
+    }
+
+    #[test]
+    fn test_encrypted_map_roundtrip() {
+        let secret = Encrypted::new(b"wallet seed");
+        let revealed = secret.map(|plaintext| plaintext.as_slice().to_vec());
+        assert_eq!(revealed, b"wallet seed");
+    }
+
+    #[test]
+    fn test_encrypted_ciphertext_not_plaintext() {
+        let secret = Encrypted::new(b"wallet seed");
+        assert_ne!(secret.ciphertext, b"wallet seed".to_vec());
+    }
+
+    #[test]
+    fn test_aead_encrypt_decrypt_roundtrip() {
+        let key = [9u8; 32];
+        let nonce = [1u8; 12];
+        let aad = b"wallet-seed-v1";
+        let plaintext = b"correct horse battery staple";
+
+        let ciphertext = aead::aes256_gcm_encrypt(&key, &nonce, aad, plaintext).unwrap();
+        assert_eq!(ciphertext.len(), plaintext.len() + 16);
+
+        let recovered = aead::aes256_gcm_decrypt(&key, &nonce, aad, &ciphertext).unwrap();
+        assert_eq!(&recovered[..], &plaintext[..]);
+    }
+
+    #[test]
+    fn test_aead_decrypt_rejects_tampered_tag() {
+        let key = [9u8; 32];
+        let nonce = [1u8; 12];
+        let aad = b"wallet-seed-v1";
+        let mut ciphertext =
+            aead::aes256_gcm_encrypt(&key, &nonce, aad, b"correct horse battery staple").unwrap();
+        let last = ciphertext.len() - 1;
+        ciphertext[last] ^= 0xff;
+
+        assert!(aead::aes256_gcm_decrypt(&key, &nonce, aad, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn test_secp256k1_secret_key_eq() {
+        let a = secp256k1::SecretKey::from_bytes([7u8; 32]);
+        let b = secp256k1::SecretKey::from_bytes([7u8; 32]);
+        let c = secp256k1::SecretKey::from_bytes([8u8; 32]);
+        assert!(a == b);
+        assert!(a != c);
+    }
 }